@@ -1,5 +1,6 @@
 use arguably::ArgParser;
 use std::fmt::Write;
+use std::io::IsTerminal;
 use std::io::Seek;
 use std::io;
 use colored::*;
@@ -25,17 +26,36 @@ Usage: hexbomb [FLAGS] [OPTIONS] [ARGUMENTS]
 
   Note that the --offset option cannot be used when reading from STDIN.
 
+  The --number and --offset options also accept hex literals prefixed with
+  '0x' and a trailing unit suffix, either decimal (kB, MB, GB) or binary
+  (kiB, MiB, GiB), e.g. --number 4KiB or --offset 0x1000.
+
+  Multiple files may be specified; each is dumped in turn. If more than one
+  file is given, each dump is preceded by a header naming the file, and a
+  file that cannot be opened is reported without aborting the others.
+
 Arguments:
-  [file]                    File to read. Defaults to reading from STDIN.
+  [files]                   Files to read. Defaults to reading from STDIN.
 
 Options:
   -l, --line <int>          Bytes per line in output (default: 16).
   -n, --number <int>        Number of bytes to read (default: all).
   -o, --offset <int>        Byte offset at which to begin reading (default: 0).
+  -b, --base <str>          Base for the byte column: hex, oct, dec, bin (default: hex).
+  -g, --group <int>         Number of bytes per whitespace-separated group (default: 1).
+  -t, --type <str>          Add a column interpreting bytes as typed values:
+                            u8, i8, u16, i16, u32, i32, u64, i64, f32, f64.
+  -e, --endian <str>        Endianness for --type values: little, big (default: little).
 
 Flags:
   -h, --help                Display this help text and exit.
   -v, --version             Display the version number and exit.
+  -s, --squeeze             Collapse runs of identical lines with a '*' marker.
+  --color                   Force colored output on.
+  --no-color                Force colored output off.
+
+  By default output is colored only when stdout is a terminal and the NO_COLOR
+  environment variable is unset.
 ";
 
 
@@ -45,33 +65,104 @@ fn main() {
         .version(env!("CARGO_PKG_VERSION"))
         .option("line l", "16")
         .option("number n", "0")
-        .option("offset o", "0");
+        .option("offset o", "0")
+        .option("base b", "hex")
+        .option("group g", "1")
+        .option("type t", "")
+        .option("endian e", "little")
+        .flag("squeeze s")
+        .flag("color")
+        .flag("no-color");
 
     // Parse the command line arguments.
     if let Err(err) = parser.parse() {
         err.exit();
     }
+
+    // Decide whether to colorize output: an explicit --color/--no-color flag wins, otherwise
+    // color is enabled only when stdout is a terminal and NO_COLOR is unset.
+    let want_color = if parser.found("no-color") {
+        false
+    } else if parser.found("color") {
+        true
+    } else {
+        std::env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal()
+    };
+    colored::control::set_override(want_color);
+
     let (num_per_line, num_to_read, offset) = args_to_ints(&parser);
     let read_all = !parser.found("number");
+    let base = parse_base(parser.value("base"));
+    let group = match parser.value("group").parse::<usize>() {
+        Ok(int_val) if int_val > 0 => int_val,
+        _ => {
+            eprintln!("Error: cannot parse '{}' as a positive integer.", parser.value("group"));
+            std::process::exit(1);
+        }
+    };
+    let type_writer = if parser.found("type") {
+        let endian = parse_endian(parser.value("endian"));
+        Some((parse_type(parser.value("type")), endian))
+    } else {
+        None
+    };
+    let squeeze = parser.found("squeeze");
 
-    // Default to reading from stdin if no filename has been specified.
+    let opts = RenderOptions { base, group, type_writer, squeeze };
+
+    // Default to reading from stdin if no filenames have been specified.
     if parser.args.len() == 0 {
         if offset != 0 {
             eprintln!("Error: STDIN does not support seeking to an offset.");
             std::process::exit(1);
         }
         let file = io::stdin();
-        dump_file(file, read_all, num_to_read, num_per_line, 0);
+        dump_file(file, read_all, num_to_read, num_per_line, 0, &opts);
         return;
     }
 
-    // If we reach this point, a filename has been specified.
-    let filepath = std::path::Path::new(&parser.args[0]);
-    let mut file = match std::fs::File::open(&filepath) {
+    // Otherwise, dump each specified file in turn. With more than one file a header naming
+    // the file precedes each dump, as with `head`. A file that fails to open is reported but
+    // does not abort the rest of the run.
+    let print_headers = parser.args.len() > 1;
+
+    for (i, path) in parser.args.iter().enumerate() {
+        if print_headers {
+            if i > 0 {
+                println!();
+            }
+            println!("==> {} <==", path);
+        }
+        dump_path(path, read_all, num_to_read, num_per_line, offset, &opts);
+    }
+}
+
+
+// Bundles the flags that control how a dump is rendered, so that adding another rendering
+// flag doesn't mean adding another bare parameter to every function along the dump path.
+#[derive(Clone, Copy)]
+struct RenderOptions {
+    base: Base,
+    group: usize,
+    type_writer: Option<(TypeWriter, Endianness)>,
+    squeeze: bool,
+}
+
+
+// Opens and dumps a single named file, seeking to the requested offset first.
+fn dump_path(
+    path: &str,
+    read_all: bool,
+    num_to_read: usize,
+    num_per_line: usize,
+    offset: i64,
+    opts: &RenderOptions,
+) {
+    let mut file = match std::fs::File::open(std::path::Path::new(path)) {
         Ok(file) => file,
         Err(_) => {
-            eprintln!("Error: cannot open the specified file.");
-            std::process::exit(1);
+            eprintln!("Error: cannot open '{}'.", path);
+            return;
         }
     };
 
@@ -83,8 +174,8 @@ fn main() {
         match file.seek(io::SeekFrom::Start(offset as u64)) {
             Ok(_) => (),
             Err(_) => {
-                eprintln!("Error: cannot seek to the specified offset.");
-                std::process::exit(1);
+                eprintln!("Error: cannot seek to the specified offset in '{}'.", path);
+                return;
             }
         };
         display_offset = offset as usize;
@@ -98,19 +189,326 @@ fn main() {
             },
             Err(err) => {
                 eprintln!("Error: {}", err);
-                std::process::exit(1);
+                return;
             }
         };
         match file.seek(io::SeekFrom::End(offset)) {
             Ok(_) => (),
             Err(_) => {
-                eprintln!("Error: cannot seek to the specified offset.");
-                std::process::exit(1);
+                eprintln!("Error: cannot seek to the specified offset in '{}'.", path);
+                return;
             }
         };
     }
 
-    dump_file(file, read_all, num_to_read, num_per_line, display_offset);
+    dump_file(file, read_all, num_to_read, num_per_line, display_offset, opts);
+}
+
+
+// Byte order used to interpret a --type column's multi-byte values.
+#[derive(Clone, Copy)]
+enum Endianness {
+    Little,
+    Big,
+}
+
+
+// Parses the --endian option's value.
+fn parse_endian(raw: &str) -> Endianness {
+    match raw {
+        "little" => Endianness::Little,
+        "big" => Endianness::Big,
+        _ => {
+            eprintln!("Error: '{}' is not a valid endianness, expected one of: little, big.", raw);
+            std::process::exit(1);
+        }
+    }
+}
+
+
+// A format writer for the --type column: consumes `width` bytes at a time and renders them
+// as an unsigned/signed integer or an IEEE float, right-aligned to a fixed display width.
+#[derive(Clone, Copy)]
+enum TypeWriter {
+    IntWriter(fn(&[u8], Endianness) -> String, usize, usize),
+    FloatWriter(fn(&[u8], Endianness) -> String, usize, usize),
+}
+
+
+impl TypeWriter {
+    // Number of raw bytes consumed per value.
+    fn width(&self) -> usize {
+        match self {
+            TypeWriter::IntWriter(_, width, _) => *width,
+            TypeWriter::FloatWriter(_, width, _) => *width,
+        }
+    }
+
+    // Display width of a single formatted value.
+    fn display_width(&self) -> usize {
+        match self {
+            TypeWriter::IntWriter(_, _, display_width) => *display_width,
+            TypeWriter::FloatWriter(_, _, display_width) => *display_width,
+        }
+    }
+
+    fn format(&self, bytes: &[u8], endian: Endianness) -> String {
+        match self {
+            TypeWriter::IntWriter(writer, _, display_width) | TypeWriter::FloatWriter(writer, _, display_width) => {
+                format!("{:>width$}", writer(bytes, endian), width = display_width)
+            }
+        }
+    }
+}
+
+
+// Parses the --type option's value.
+fn parse_type(raw: &str) -> TypeWriter {
+    match raw {
+        "u8" => TypeWriter::IntWriter(format_u8, 1, 3),
+        "i8" => TypeWriter::IntWriter(format_i8, 1, 4),
+        "u16" => TypeWriter::IntWriter(format_u16, 2, 5),
+        "i16" => TypeWriter::IntWriter(format_i16, 2, 6),
+        "u32" => TypeWriter::IntWriter(format_u32, 4, 10),
+        "i32" => TypeWriter::IntWriter(format_i32, 4, 11),
+        "u64" => TypeWriter::IntWriter(format_u64, 8, 20),
+        "i64" => TypeWriter::IntWriter(format_i64, 8, 20),
+        "f32" => TypeWriter::FloatWriter(format_f32, 4, 13),
+        "f64" => TypeWriter::FloatWriter(format_f64, 8, 14),
+        _ => {
+            eprintln!(
+                "Error: '{}' is not a valid type, expected one of: \
+                u8, i8, u16, i16, u32, i32, u64, i64, f32, f64.",
+                raw
+            );
+            std::process::exit(1);
+        }
+    }
+}
+
+
+fn format_u8(bytes: &[u8], _endian: Endianness) -> String {
+    format!("{}", bytes[0])
+}
+
+
+fn format_i8(bytes: &[u8], _endian: Endianness) -> String {
+    format!("{}", bytes[0] as i8)
+}
+
+
+fn format_u16(bytes: &[u8], endian: Endianness) -> String {
+    let array = [bytes[0], bytes[1]];
+    let value = match endian {
+        Endianness::Little => u16::from_le_bytes(array),
+        Endianness::Big => u16::from_be_bytes(array),
+    };
+    format!("{}", value)
+}
+
+
+fn format_i16(bytes: &[u8], endian: Endianness) -> String {
+    let array = [bytes[0], bytes[1]];
+    let value = match endian {
+        Endianness::Little => i16::from_le_bytes(array),
+        Endianness::Big => i16::from_be_bytes(array),
+    };
+    format!("{}", value)
+}
+
+
+fn format_u32(bytes: &[u8], endian: Endianness) -> String {
+    let array = [bytes[0], bytes[1], bytes[2], bytes[3]];
+    let value = match endian {
+        Endianness::Little => u32::from_le_bytes(array),
+        Endianness::Big => u32::from_be_bytes(array),
+    };
+    format!("{}", value)
+}
+
+
+fn format_i32(bytes: &[u8], endian: Endianness) -> String {
+    let array = [bytes[0], bytes[1], bytes[2], bytes[3]];
+    let value = match endian {
+        Endianness::Little => i32::from_le_bytes(array),
+        Endianness::Big => i32::from_be_bytes(array),
+    };
+    format!("{}", value)
+}
+
+
+fn format_u64(bytes: &[u8], endian: Endianness) -> String {
+    let array = [
+        bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+    ];
+    let value = match endian {
+        Endianness::Little => u64::from_le_bytes(array),
+        Endianness::Big => u64::from_be_bytes(array),
+    };
+    format!("{}", value)
+}
+
+
+fn format_i64(bytes: &[u8], endian: Endianness) -> String {
+    let array = [
+        bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+    ];
+    let value = match endian {
+        Endianness::Little => i64::from_le_bytes(array),
+        Endianness::Big => i64::from_be_bytes(array),
+    };
+    format!("{}", value)
+}
+
+
+fn format_f32(bytes: &[u8], endian: Endianness) -> String {
+    let array = [bytes[0], bytes[1], bytes[2], bytes[3]];
+    let bits = match endian {
+        Endianness::Little => u32::from_le_bytes(array),
+        Endianness::Big => u32::from_be_bytes(array),
+    };
+    // Scientific notation bounds the field width regardless of exponent, unlike fixed-point
+    // `{:.6}`, which can run to dozens of characters for an arbitrary f32 bit pattern (and
+    // to hundreds for an f64).
+    format!("{:.6e}", f32::from_bits(bits))
+}
+
+
+fn format_f64(bytes: &[u8], endian: Endianness) -> String {
+    let array = [
+        bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+    ];
+    let bits = match endian {
+        Endianness::Little => u64::from_le_bytes(array),
+        Endianness::Big => u64::from_be_bytes(array),
+    };
+    format!("{:.6e}", f64::from_bits(bits))
+}
+
+
+// Total width in characters of the --type column's byte area, including separating spaces.
+fn type_column_width(num_per_line: usize, writer: &TypeWriter) -> usize {
+    let num_values = num_per_line.div_ceil(writer.width());
+    if num_values == 0 {
+        0
+    } else {
+        num_values * writer.display_width() + (num_values - 1)
+    }
+}
+
+
+// Renders the --type column for a single line. A value is only formatted when all of its
+// bytes fall within `num_bytes`; otherwise its field is left blank, since the buffer beyond
+// `num_bytes` holds stale bytes from a previous read rather than real data.
+fn type_column(bytes: &[u8], num_bytes: usize, num_per_line: usize, writer: &TypeWriter, endian: Endianness) -> String {
+    let width = writer.width();
+    let num_values = num_per_line.div_ceil(width);
+    let mut out = String::new();
+
+    for i in 0..num_values {
+        if i > 0 {
+            out.push(' ');
+        }
+        let start = i * width;
+        if start + width <= num_bytes {
+            out.push_str(&writer.format(&bytes[start..start + width], endian));
+        } else {
+            out.push_str(&" ".repeat(writer.display_width()));
+        }
+    }
+
+    return out;
+}
+
+
+// The base used to render each byte in the byte column.
+#[derive(Clone, Copy)]
+enum Base {
+    Hex,
+    Oct,
+    Dec,
+    Bin,
+}
+
+
+impl Base {
+    // Width in characters of a single formatted byte, not counting separators.
+    fn field_width(&self) -> usize {
+        match self {
+            Base::Hex => 2,
+            Base::Oct => 3,
+            Base::Dec => 3,
+            Base::Bin => 8,
+        }
+    }
+
+    fn format_byte(&self, byte: u8) -> String {
+        match self {
+            Base::Hex => format!("{:02X}", byte),
+            Base::Oct => format!("{:03o}", byte),
+            Base::Dec => format!("{:3}", byte),
+            Base::Bin => format!("{:08b}", byte),
+        }
+    }
+}
+
+
+// Parses the --base option's value.
+fn parse_base(raw: &str) -> Base {
+    match raw {
+        "hex" => Base::Hex,
+        "oct" => Base::Oct,
+        "dec" => Base::Dec,
+        "bin" => Base::Bin,
+        _ => {
+            eprintln!("Error: '{}' is not a valid base, expected one of: hex, oct, dec, bin.", raw);
+            std::process::exit(1);
+        }
+    }
+}
+
+
+// Categorizes a byte for coloring purposes, borrowing hexyl's approach so that structure in
+// binaries is visible at a glance in both the byte column and the character column.
+#[derive(Clone, Copy)]
+enum ByteCategory {
+    Null,
+    Printable,
+    Whitespace,
+    Other,
+}
+
+
+fn classify_byte(byte: u8) -> ByteCategory {
+    match byte {
+        0x00 => ByteCategory::Null,
+        0x09 | 0x0A | 0x0B | 0x0C | 0x0D | 0x20 => ByteCategory::Whitespace,
+        0x21..=0x7E => ByteCategory::Printable,
+        _ => ByteCategory::Other,
+    }
+}
+
+
+// Colors `text` according to the category of the byte it represents. Used by both the byte
+// column and the character column so the two stay consistent.
+fn colorize_byte(byte: u8, text: &str) -> ColoredString {
+    match classify_byte(byte) {
+        ByteCategory::Null => text.bright_black(),
+        ByteCategory::Printable => text.green(),
+        ByteCategory::Whitespace => text.yellow(),
+        ByteCategory::Other => text.red(),
+    }
+}
+
+
+// Number of characters a byte at line-position `i` occupies in the byte column, including
+// its separating space if `i` falls on a group boundary.
+fn byte_field_width(i: usize, group: usize, field_width: usize) -> usize {
+    if i.is_multiple_of(group) {
+        1 + field_width
+    } else {
+        field_width
+    }
 }
 
 
@@ -123,14 +521,14 @@ fn args_to_ints(parser: &ArgParser) -> (usize, usize, i64) {
             std::process::exit(1);
         }
     };
-    let num_to_read = match parser.value("number").parse::<usize>() {
-        Ok(int_val) => int_val,
-        Err(_) => {
+    let num_to_read = match parse_sized_int(parser.value("number")) {
+        Ok(int_val) if int_val >= 0 => int_val as usize,
+        _ => {
             eprintln!("Error: cannot parse '{}' as a positive integer.", parser.value("number"));
             std::process::exit(1);
         }
     };
-    let offset = match parser.value("offset").parse::<i64>() {
+    let offset = match parse_sized_int(parser.value("offset")) {
         Ok(int_val) => int_val,
         Err(_) => {
             eprintln!("Error: cannot parse '{}' as an integer.", parser.value("offset"));
@@ -141,12 +539,117 @@ fn args_to_ints(parser: &ArgParser) -> (usize, usize, i64) {
 }
 
 
+// Parses a (possibly signed) integer argument, accepting hex literals prefixed with '0x'
+// and a trailing decimal (kB/MB/GB) or binary (kiB/MiB/GiB) unit suffix, e.g. "4KiB", "0xFF".
+fn parse_sized_int(raw: &str) -> Result<i64, ()> {
+    let (raw, negative) = match raw.strip_prefix('-') {
+        Some(rest) => (rest, true),
+        None => (raw, false),
+    };
+
+    let (digits, multiplier): (&str, i64) = if let Some(rest) = strip_suffix_ci(raw, "kib") {
+        (rest, 1024)
+    } else if let Some(rest) = strip_suffix_ci(raw, "mib") {
+        (rest, 1024 * 1024)
+    } else if let Some(rest) = strip_suffix_ci(raw, "gib") {
+        (rest, 1024 * 1024 * 1024)
+    } else if let Some(rest) = strip_suffix_ci(raw, "kb") {
+        (rest, 1000)
+    } else if let Some(rest) = strip_suffix_ci(raw, "mb") {
+        (rest, 1000 * 1000)
+    } else if let Some(rest) = strip_suffix_ci(raw, "gb") {
+        (rest, 1000 * 1000 * 1000)
+    } else {
+        (raw, 1)
+    };
+
+    let value = if let Some(hex_digits) = digits.strip_prefix("0x") {
+        i64::from_str_radix(hex_digits, 16).map_err(|_| ())?
+    } else {
+        digits.parse::<i64>().map_err(|_| ())?
+    };
+
+    let value = value.checked_mul(multiplier).ok_or(())?;
+    Ok(if negative { -value } else { value })
+}
+
+
+// Case-insensitively strips a unit suffix, returning the remaining digits.
+fn strip_suffix_ci<'a>(raw: &'a str, suffix: &str) -> Option<&'a str> {
+    if raw.len() > suffix.len() && raw[raw.len() - suffix.len()..].eq_ignore_ascii_case(suffix) {
+        Some(&raw[..raw.len() - suffix.len()])
+    } else {
+        None
+    }
+}
+
+
+// Size of the internal read-ahead buffer used by ChunkReader.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+
+// Wraps a reader with a large internal buffer so that individual output rows can be sliced
+// out of memory instead of costing one syscall per row, which is what made dumping large
+// files or pipes through a bare `Read::read` call per line so slow.
+struct ChunkReader<T: io::Read> {
+    source: T,
+    chunk: Vec<u8>,
+    pos: usize,
+    filled: usize,
+    eof: bool,
+}
+
+
+impl<T: io::Read> ChunkReader<T> {
+    fn new(source: T) -> Self {
+        ChunkReader {
+            source,
+            chunk: vec![0; CHUNK_SIZE],
+            pos: 0,
+            filled: 0,
+            eof: false,
+        }
+    }
+
+    // Fills `row` from the internal chunk buffer, refilling it from the underlying reader as
+    // needed, and carrying any leftover bytes across refills. Returns the number of bytes
+    // written, which is only less than `row.len()` once the underlying reader is exhausted.
+    fn read_row(&mut self, row: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+
+        while written < row.len() {
+            if self.pos == self.filled {
+                if self.eof {
+                    break;
+                }
+                self.filled = self.source.read(&mut self.chunk)?;
+                self.pos = 0;
+                if self.filled == 0 {
+                    self.eof = true;
+                    break;
+                }
+            }
+
+            let available = self.filled - self.pos;
+            let needed = row.len() - written;
+            let take = available.min(needed);
+            row[written..written + take].copy_from_slice(&self.chunk[self.pos..self.pos + take]);
+            self.pos += take;
+            written += take;
+        }
+
+        Ok(written)
+    }
+}
+
+
 fn dump_file<T: io::Read>(
-    mut file: T,
+    file: T,
     read_all: bool,
     num_to_read: usize,
     num_per_line: usize,
-    display_offset: usize
+    display_offset: usize,
+    opts: &RenderOptions,
 ) {
     // Number of bytes remaining to be read, if we're reading a fixed number.
     let mut bytes_remaining = if read_all { usize::MAX } else { num_to_read };
@@ -157,7 +660,16 @@ fn dump_file<T: io::Read>(
     // Buffer for storing file input.
     let mut buffer: Vec<u8> = vec![0; num_per_line];
 
-    println!("{}", top_line(num_per_line));
+    let mut reader = ChunkReader::new(file);
+
+    // When squeezing, a row is held back by one iteration so that it can still be printed in
+    // full if it turns out to be the last row in the stream, even if it matches the previous
+    // one. `prev_printed` and `marker_printed` track the run of rows actually printed so far.
+    let mut held: Option<(Vec<u8>, usize, usize)> = None;
+    let mut prev_printed: Option<Vec<u8>> = None;
+    let mut marker_printed = false;
+
+    println!("{}", top_line(num_per_line, opts));
 
     loop {
         // Determine the maximum number of bytes to read this iteration.
@@ -169,14 +681,33 @@ fn dump_file<T: io::Read>(
             bytes_remaining
         };
 
-        // Attempt to read up to max_bytes from the file.
-        match file.read(&mut buffer[0..max_bytes]) {
+        if max_bytes == 0 {
+            break;
+        }
+
+        // Attempt to fill a full row of max_bytes from the chunk buffer.
+        match reader.read_row(&mut buffer[0..max_bytes]) {
             Ok(num_bytes) => {
                 if num_bytes > 0 {
-                    println!("{}", line(&buffer, num_bytes, display_offset + bytes_read, num_per_line));
+                    let row_offset = display_offset + bytes_read;
                     bytes_read += num_bytes;
                     bytes_remaining -= num_bytes;
-                } else {
+
+                    if opts.squeeze {
+                        if let Some((held_bytes, held_num, held_offset)) = held.take() {
+                            emit_row(
+                                &held_bytes, held_num, held_offset, false,
+                                num_per_line, opts,
+                                &mut prev_printed, &mut marker_printed,
+                            );
+                        }
+                        held = Some((buffer[0..num_bytes].to_vec(), num_bytes, row_offset));
+                    } else {
+                        println!("{}", line(&buffer, num_bytes, row_offset, num_per_line, opts));
+                    }
+                }
+                // A short row means the underlying reader is exhausted.
+                if num_bytes < max_bytes {
                     break;
                 }
             },
@@ -187,22 +718,60 @@ fn dump_file<T: io::Read>(
         }
     }
 
+    // The final held row is always printed in full, even if it matches the row before it.
+    if let Some((held_bytes, held_num, held_offset)) = held.take() {
+        emit_row(
+            &held_bytes, held_num, held_offset, true,
+            num_per_line, opts,
+            &mut prev_printed, &mut marker_printed,
+        );
+    }
+
     if bytes_read == 0 {
-        println!("{}", empty_line(display_offset, num_per_line));
+        println!("{}", empty_line(display_offset, num_per_line, opts));
     }
 
-    println!("{}", bottom_line(num_per_line));
+    println!("{}", bottom_line(num_per_line, opts));
 }
 
 
-fn top_line(num_per_line: usize) -> String {
+// Prints a single row while squeezing: a row that byte-for-byte matches the previously
+// printed row is suppressed (after a single '*' marker line), unless `force` is set.
+fn emit_row(
+    bytes: &[u8],
+    num_bytes: usize,
+    offset: usize,
+    force: bool,
+    num_per_line: usize,
+    opts: &RenderOptions,
+    prev_printed: &mut Option<Vec<u8>>,
+    marker_printed: &mut bool,
+) {
+    let content = &bytes[0..num_bytes];
+    let is_repeat = !force && prev_printed.as_deref() == Some(content);
+
+    if is_repeat {
+        if !*marker_printed {
+            println!("{}", marker_line(num_per_line, opts));
+            *marker_printed = true;
+        }
+    } else {
+        println!("{}", line(bytes, num_bytes, offset, num_per_line, opts));
+        *prev_printed = Some(content.to_vec());
+        *marker_printed = false;
+    }
+}
+
+
+fn top_line(num_per_line: usize, opts: &RenderOptions) -> String {
+    let field_width = opts.base.field_width();
     let mut line = String::from("┌──────────┬");
 
     for i in 0..num_per_line {
         if i > 0 && i % 8 == 0 {
             line.push_str("──");
         }
-        line.push_str("───");
+        line.push_str(&"─".repeat(byte_field_width(i, opts.group, field_width)));
     }
 
     line.push_str("─┬─");
@@ -214,19 +783,30 @@ fn top_line(num_per_line: usize) -> String {
         line.push_str("─");
     }
 
-    line.push_str("─┐");
+    if opts.type_writer.is_some() {
+        line.push_str("─┬");
+    } else {
+        line.push_str("─┐");
+    }
+
+    if let Some((writer, _)) = &opts.type_writer {
+        line.push_str(&"─".repeat(type_column_width(num_per_line, writer) + 2));
+        line.push('┐');
+    }
+
     return line.bright_black().to_string();
 }
 
 
-fn bottom_line(num_per_line: usize) -> String {
-   let mut line = String::from("└──────────┴");
+fn bottom_line(num_per_line: usize, opts: &RenderOptions) -> String {
+    let field_width = opts.base.field_width();
+    let mut line = String::from("└──────────┴");
 
     for i in 0..num_per_line {
         if i > 0 && i % 8 == 0 {
             line.push_str("──");
         }
-        line.push_str("───");
+        line.push_str(&"─".repeat(byte_field_width(i, opts.group, field_width)));
     }
 
     line.push_str("─┴─");
@@ -238,19 +818,63 @@ fn bottom_line(num_per_line: usize) -> String {
         line.push_str("─");
     }
 
-    line.push_str("─┘");
+    if opts.type_writer.is_some() {
+        line.push_str("─┴");
+    } else {
+        line.push_str("─┘");
+    }
+
+    if let Some((writer, _)) = &opts.type_writer {
+        line.push_str(&"─".repeat(type_column_width(num_per_line, writer) + 2));
+        line.push('┘');
+    }
+
     return line.bright_black().to_string();
 }
 
 
-fn empty_line(offset: usize, num_per_line: usize) -> String{
+fn empty_line(offset: usize, num_per_line: usize, opts: &RenderOptions) -> String {
+    let field_width = opts.base.field_width();
     let mut line = format!("│ {:width$X} │", offset, width = 8);
 
     for i in 0..num_per_line {
         if i > 0 && i % 8 == 0 {
             line.push_str("  ");
         }
-        line.push_str("   ");
+        line.push_str(&" ".repeat(byte_field_width(i, opts.group, field_width)));
+    }
+
+    line.push_str(" │ ");
+
+    for i in 0..num_per_line {
+        if i > 0 && i % 8 == 0 {
+            line.push_str(" ");
+        }
+        line.push_str(" ");
+    }
+
+    line.push_str(" │");
+
+    if let Some((writer, _)) = &opts.type_writer {
+        line.push_str(&" ".repeat(type_column_width(num_per_line, writer) + 2));
+        line.push_str("│");
+    }
+
+    return line.bright_black().to_string();
+}
+
+
+// A single '*' marker line, boxed to match the borders, standing in for a run of squeezed
+// rows that are byte-identical to the row before them.
+fn marker_line(num_per_line: usize, opts: &RenderOptions) -> String {
+    let field_width = opts.base.field_width();
+    let mut line = format!("│ {:>8} │", "*");
+
+    for i in 0..num_per_line {
+        if i > 0 && i % 8 == 0 {
+            line.push_str("  ");
+        }
+        line.push_str(&" ".repeat(byte_field_width(i, opts.group, field_width)));
     }
 
     line.push_str(" │ ");
@@ -263,6 +887,12 @@ fn empty_line(offset: usize, num_per_line: usize) -> String{
     }
 
     line.push_str(" │");
+
+    if let Some((writer, _)) = &opts.type_writer {
+        line.push_str(&" ".repeat(type_column_width(num_per_line, writer) + 2));
+        line.push_str("│");
+    }
+
     return line.bright_black().to_string();
 }
 
@@ -281,17 +911,21 @@ fn line_number(offset: usize) -> String {
 }
 
 
-fn line(bytes: &[u8], num_bytes: usize, offset: usize, num_per_line: usize) -> String {
+fn line(bytes: &[u8], num_bytes: usize, offset: usize, num_per_line: usize, opts: &RenderOptions) -> String {
+    let field_width = opts.base.field_width();
     let mut line = format!("{1}{0} {1}", line_number(offset), "│".bright_black());
 
     for i in 0..num_per_line {
         if i > 0 && i % 8 == 0 {
             line.push_str(&" ┆".bright_black().to_string());
         }
+        if i.is_multiple_of(opts.group) {
+            line.push(' ');
+        }
         if i < num_bytes {
-            write!(line, " {:02X}", bytes[i]).unwrap();
+            write!(line, "{}", colorize_byte(bytes[i], &opts.base.format_byte(bytes[i]))).unwrap();
         } else {
-            line.push_str("   ");
+            line.push_str(&" ".repeat(field_width));
         }
     }
 
@@ -303,9 +937,9 @@ fn line(bytes: &[u8], num_bytes: usize, offset: usize, num_per_line: usize) -> S
         }
         if i < num_bytes {
             if bytes[i] > 31 && bytes[i] < 127 {
-                line.push(bytes[i] as char);
+                write!(line, "{}", colorize_byte(bytes[i], &(bytes[i] as char).to_string())).unwrap();
             } else {
-                line.push_str(&"·".bright_black().to_string());
+                write!(line, "{}", colorize_byte(bytes[i], "·")).unwrap();
             }
         } else {
             line.push_str(" ");
@@ -313,6 +947,11 @@ fn line(bytes: &[u8], num_bytes: usize, offset: usize, num_per_line: usize) -> S
     }
 
     line.push_str(&" │".bright_black().to_string());
+
+    if let Some((writer, endian)) = &opts.type_writer {
+        write!(line, " {} │", type_column(bytes, num_bytes, num_per_line, writer, *endian)).unwrap();
+    }
+
     return line;
 }
 